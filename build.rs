@@ -83,6 +83,13 @@ fn build_licenses_from_json(input: &Path, output: &Path) -> Result<(), Box<dyn E
     f.write_all(b"    }\n")?;
     f.write_all(b"}\n\n")?;
 
+    // Generate a slice of every embedded license, used for reverse lookups.
+    f.write_all(b"pub(crate) static ALL: &[&dyn crate::License] = &[\n")?;
+    for license in &licenses {
+        writeln!(f, "    &{},", license.ident())?;
+    }
+    f.write_all(b"];\n\n")?;
+
     // Generate the license code.
     for license in licenses {
         writeln!(
@@ -0,0 +1,518 @@
+//! SPDX license expression parsing.
+//!
+//! An [`Expression`] is the parsed form of strings such as
+//! `"MIT OR Apache-2.0"`, `"Unlicense/MIT"` or `"GPL-2.0-or-later WITH Classpath-exception-2.0"`,
+//! as commonly found in a crate's `license` metadata.
+//!
+//! Requires the `alloc` feature.
+
+use crate::{exceptions, licenses, Exception, License};
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter};
+
+/// A parsed SPDX license expression.
+///
+/// # Examples
+/// ```
+/// use license::expression::Expression;
+///
+/// let expr = Expression::parse("MIT OR Apache-2.0").unwrap();
+/// assert!(expr.iter_licenses().any(|license| license.id() == "MIT"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Expression {
+    expr: Expr,
+}
+
+impl Expression {
+    /// Parses an SPDX license expression.
+    pub fn parse(s: &str) -> Result<Self, Error> {
+        let tokens = tokenize(s)?;
+        let mut parser = Parser {
+            tokens: &tokens,
+            pos: 0,
+        };
+        let expr = parser.parse_or()?;
+        if parser.pos != parser.tokens.len() {
+            let offset = parser.tokens[parser.pos].offset;
+            return Err(Error::new(offset, ErrorKind::UnexpectedToken));
+        }
+        Ok(Expression { expr })
+    }
+
+    /// Iterates over every license referenced by this expression.
+    ///
+    /// Custom `LicenseRef-` references are not yielded, since they do not
+    /// resolve to an embedded [`License`].
+    pub fn iter_licenses(&self) -> impl Iterator<Item = &'static dyn License> + '_ {
+        let mut licenses = Vec::new();
+        self.expr.collect_licenses(&mut licenses);
+        licenses.into_iter()
+    }
+
+    /// Says if `held` contains enough licenses to satisfy this expression.
+    ///
+    /// An `AND` requires both sides to be satisfied, an `OR` requires either
+    /// side, and a leaf license is satisfied if a matching id is present in
+    /// `held` (a `+` on the leaf also matches later versions of the same license).
+    #[inline]
+    pub fn is_satisfied_by(&self, held: &[&dyn License]) -> bool {
+        self.expr.is_satisfied_by(held)
+    }
+
+    /// Returns a set of licenses from `held` that satisfy this expression.
+    ///
+    /// An `OR` takes the smaller of the sets that satisfy its two sides (the
+    /// left one on a tie) and an `AND` takes the union of both sides' sets, so
+    /// the result is not always the smallest possible set: for
+    /// `MIT AND (Apache-2.0 OR MIT)` with both licenses held, it is
+    /// `[MIT, Apache-2.0]`.
+    ///
+    /// Returns `None` if `held` does not satisfy the expression.
+    #[inline]
+    pub fn satisfying_licenses<'a>(&self, held: &[&'a dyn License]) -> Option<Vec<&'a dyn License>> {
+        self.expr.satisfying_licenses(held)
+    }
+}
+
+/// A node in the abstract syntax tree of an [`Expression`].
+#[derive(Clone, Debug)]
+pub enum Expr {
+    /// A single license, e.g. `MIT` or `GPL-2.0-only+`.
+    License {
+        /// The referenced license.
+        license: &'static dyn License,
+        /// Says if a trailing `+` allows later versions of the license.
+        or_later: bool,
+    },
+    /// A custom `LicenseRef-...` reference that does not resolve to an embedded license.
+    Ref(String),
+    /// A license combined with an exception, e.g. `GPL-2.0-only WITH Classpath-exception-2.0`.
+    ///
+    /// The trailing `bool` mirrors the `License` variant's `or_later`: a `+`
+    /// on the license before `WITH` also allows later versions of its family.
+    With(&'static dyn License, &'static dyn Exception, bool),
+    /// Both sides must be satisfied.
+    And(Box<Expr>, Box<Expr>),
+    /// Either side may be satisfied.
+    Or(Box<Expr>, Box<Expr>),
+}
+
+impl Expr {
+    fn collect_licenses<'a>(&'a self, out: &mut Vec<&'static dyn License>) {
+        match self {
+            Expr::License { license, .. } | Expr::With(license, ..) => out.push(*license),
+            Expr::Ref(_) => {}
+            Expr::And(lhs, rhs) | Expr::Or(lhs, rhs) => {
+                lhs.collect_licenses(out);
+                rhs.collect_licenses(out);
+            }
+        }
+    }
+
+    fn is_satisfied_by(&self, held: &[&dyn License]) -> bool {
+        match self {
+            Expr::License { license, or_later } => held
+                .iter()
+                .any(|held| license_matches(held.id(), license.id(), *or_later)),
+            Expr::With(license, _, or_later) => held
+                .iter()
+                .any(|held| license_matches(held.id(), license.id(), *or_later)),
+            Expr::Ref(_) => false,
+            Expr::And(lhs, rhs) => lhs.is_satisfied_by(held) && rhs.is_satisfied_by(held),
+            Expr::Or(lhs, rhs) => lhs.is_satisfied_by(held) || rhs.is_satisfied_by(held),
+        }
+    }
+
+    fn satisfying_licenses<'a>(&self, held: &[&'a dyn License]) -> Option<Vec<&'a dyn License>> {
+        match self {
+            Expr::License { license, or_later } => held
+                .iter()
+                .find(|held| license_matches(held.id(), license.id(), *or_later))
+                .map(|held| {
+                    let mut v = Vec::with_capacity(1);
+                    v.push(*held);
+                    v
+                }),
+            Expr::With(license, _, or_later) => held
+                .iter()
+                .find(|held| license_matches(held.id(), license.id(), *or_later))
+                .map(|held| {
+                    let mut v = Vec::with_capacity(1);
+                    v.push(*held);
+                    v
+                }),
+            Expr::Ref(_) => None,
+            Expr::And(lhs, rhs) => {
+                let mut lhs = lhs.satisfying_licenses(held)?;
+                for license in rhs.satisfying_licenses(held)? {
+                    if !lhs.iter().any(|held| held.id() == license.id()) {
+                        lhs.push(license);
+                    }
+                }
+                Some(lhs)
+            }
+            Expr::Or(lhs, rhs) => match (lhs.satisfying_licenses(held), rhs.satisfying_licenses(held)) {
+                (Some(lhs), Some(rhs)) => Some(if rhs.len() < lhs.len() { rhs } else { lhs }),
+                (Some(lhs), None) => Some(lhs),
+                (None, Some(rhs)) => Some(rhs),
+                (None, None) => None,
+            },
+        }
+    }
+}
+
+/// Says if `held_id` satisfies a requirement for `wanted_id`.
+///
+/// When `or_later` is set, ids in the same family with an equal or later
+/// version also match, so `GPL-2.0-only+` is satisfied by a held
+/// `GPL-3.0-only` but not by a held `GPL-1.0-only`.
+fn license_matches(held_id: &str, wanted_id: &str, or_later: bool) -> bool {
+    let version = |id: &str, family: &str| -> Vec<u32> {
+        let stem = id.strip_suffix("-or-later").or_else(|| id.strip_suffix("-only")).unwrap_or(id);
+        stem[family.len() + 1..].split('.').map(|part| part.parse().unwrap_or(0)).collect()
+    };
+    held_id == wanted_id
+        || match (family(held_id), family(wanted_id)) {
+            (Some(held_family), Some(wanted_family)) if or_later && held_family == wanted_family => {
+                version(held_id, held_family) >= version(wanted_id, wanted_family)
+            }
+            _ => false,
+        }
+}
+
+/// Returns the license family used for `or_later` matching, or `None` if
+/// `id` is not of the form `<family>-<version>` (optionally suffixed with
+/// `-only`/`-or-later`).
+///
+/// Only ids of that shape have interchangeable later versions, e.g.
+/// `GPL-2.0-only`/`GPL-3.0-only` or legacy `Apache-1.1`/`Apache-2.0`. Ids
+/// like `BSD-2-Clause`/`BSD-3-Clause` are unrelated license texts, not
+/// versions of one license, and must not be treated as a family.
+fn family(id: &str) -> Option<&str> {
+    let stem = id
+        .strip_suffix("-or-later")
+        .or_else(|| id.strip_suffix("-only"))
+        .unwrap_or(id);
+    let dash = stem.rfind('-')?;
+    let (name, version) = (&stem[..dash], &stem[dash + 1..]);
+    let is_version = !version.is_empty() && version.chars().all(|c| c.is_ascii_digit() || c == '.');
+    is_version.then_some(name)
+}
+
+/// Error returned when parsing an [`Expression`] fails.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Error {
+    offset: usize,
+    kind: ErrorKind,
+}
+
+impl Error {
+    const fn new(offset: usize, kind: ErrorKind) -> Self {
+        Error { offset, kind }
+    }
+
+    /// The byte offset into the input at which the error occurred.
+    #[inline]
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum ErrorKind {
+    UnknownLicense,
+    UnknownException,
+    UnexpectedToken,
+    UnexpectedEnd,
+    ExpectedException,
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let msg = match self.kind {
+            ErrorKind::UnknownLicense => "unknown SPDX license id",
+            ErrorKind::UnknownException => "unknown SPDX exception id",
+            ErrorKind::UnexpectedToken => "unexpected token",
+            ErrorKind::UnexpectedEnd => "unexpected end of expression",
+            ErrorKind::ExpectedException => "expected an exception id after `WITH`",
+        };
+        write!(f, "{msg} at byte offset {}", self.offset)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Tok<'a> {
+    kind: TokKind<'a>,
+    offset: usize,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum TokKind<'a> {
+    Id(&'a str),
+    Plus,
+    And,
+    Or,
+    With,
+    LParen,
+    RParen,
+}
+
+fn tokenize(s: &str) -> Result<Vec<Tok<'_>>, Error> {
+    let mut tokens = Vec::new();
+    let bytes = s.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b' ' | b'\t' | b'\n' | b'\r' => i += 1,
+            b'(' => {
+                tokens.push(Tok { kind: TokKind::LParen, offset: i });
+                i += 1;
+            }
+            b')' => {
+                tokens.push(Tok { kind: TokKind::RParen, offset: i });
+                i += 1;
+            }
+            b'+' => {
+                tokens.push(Tok { kind: TokKind::Plus, offset: i });
+                i += 1;
+            }
+            // Legacy `/` separator, e.g. `"Unlicense/MIT"`, read as `OR`.
+            b'/' => {
+                tokens.push(Tok { kind: TokKind::Or, offset: i });
+                i += 1;
+            }
+            _ => {
+                let start = i;
+                while i < bytes.len() && is_id_byte(bytes[i]) {
+                    i += 1;
+                }
+                if i == start {
+                    return Err(Error::new(i, ErrorKind::UnexpectedToken));
+                }
+                let word = &s[start..i];
+                let kind = if word.eq_ignore_ascii_case("AND") {
+                    TokKind::And
+                } else if word.eq_ignore_ascii_case("OR") {
+                    TokKind::Or
+                } else if word.eq_ignore_ascii_case("WITH") {
+                    TokKind::With
+                } else {
+                    TokKind::Id(word)
+                };
+                tokens.push(Tok { kind, offset: start });
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+fn is_id_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || matches!(b, b'.' | b'-' | b':')
+}
+
+struct Parser<'a> {
+    tokens: &'a [Tok<'a>],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<Tok<'a>> {
+        self.tokens.get(self.pos).copied()
+    }
+
+    fn advance(&mut self) -> Option<Tok<'a>> {
+        let tok = self.peek();
+        if tok.is_some() {
+            self.pos += 1;
+        }
+        tok
+    }
+
+    fn eof_offset(&self) -> usize {
+        self.tokens.last().map_or(0, |tok| {
+            let len = match tok.kind {
+                TokKind::Id(id) => id.len(),
+                TokKind::And => 3,
+                TokKind::Or => 2,
+                TokKind::With => 4,
+                TokKind::Plus | TokKind::LParen | TokKind::RParen => 1,
+            };
+            tok.offset + len
+        })
+    }
+
+    fn parse_or(&mut self) -> Result<Expr, Error> {
+        let mut lhs = self.parse_and()?;
+        while matches!(self.peek().map(|tok| tok.kind), Some(TokKind::Or)) {
+            self.advance();
+            let rhs = self.parse_and()?;
+            lhs = Expr::Or(Box::new(lhs), Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    fn parse_and(&mut self) -> Result<Expr, Error> {
+        let mut lhs = self.parse_with()?;
+        while matches!(self.peek().map(|tok| tok.kind), Some(TokKind::And)) {
+            self.advance();
+            let rhs = self.parse_with()?;
+            lhs = Expr::And(Box::new(lhs), Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    fn parse_with(&mut self) -> Result<Expr, Error> {
+        let mut lhs = self.parse_primary()?;
+        while let Some(Tok { kind: TokKind::With, offset }) = self.peek() {
+            self.advance();
+            let (license, or_later) = match lhs {
+                Expr::License { license, or_later } => (license, or_later),
+                _ => return Err(Error::new(offset, ErrorKind::UnexpectedToken)),
+            };
+            let tok = self
+                .advance()
+                .ok_or_else(|| Error::new(self.eof_offset(), ErrorKind::ExpectedException))?;
+            let id = match tok.kind {
+                TokKind::Id(id) => id,
+                _ => return Err(Error::new(tok.offset, ErrorKind::ExpectedException)),
+            };
+            let exception = exceptions::parse_id(id)
+                .ok_or_else(|| Error::new(tok.offset, ErrorKind::UnknownException))?;
+            lhs = Expr::With(license, exception, or_later);
+        }
+        Ok(lhs)
+    }
+
+    fn parse_primary(&mut self) -> Result<Expr, Error> {
+        let tok = self
+            .advance()
+            .ok_or_else(|| Error::new(self.eof_offset(), ErrorKind::UnexpectedEnd))?;
+        match tok.kind {
+            TokKind::LParen => {
+                let expr = self.parse_or()?;
+                match self.advance() {
+                    Some(Tok { kind: TokKind::RParen, .. }) => Ok(expr),
+                    Some(tok) => Err(Error::new(tok.offset, ErrorKind::UnexpectedToken)),
+                    None => Err(Error::new(self.eof_offset(), ErrorKind::UnexpectedEnd)),
+                }
+            }
+            TokKind::Id(id) => {
+                if id.starts_with("LicenseRef-") {
+                    return Ok(Expr::Ref(String::from(id)));
+                }
+                let license =
+                    licenses::parse_id(id).ok_or_else(|| Error::new(tok.offset, ErrorKind::UnknownLicense))?;
+                let or_later = matches!(self.peek().map(|tok| tok.kind), Some(TokKind::Plus));
+                if or_later {
+                    self.advance();
+                }
+                Ok(Expr::License { license, or_later })
+            }
+            _ => Err(Error::new(tok.offset, ErrorKind::UnexpectedToken)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn license(id: &str) -> &'static dyn License {
+        id.parse().unwrap()
+    }
+
+    #[test]
+    fn parses_a_single_license() {
+        let expr = Expression::parse("MIT").unwrap();
+        let licenses: Vec<_> = expr.iter_licenses().collect();
+        assert_eq!(licenses.len(), 1);
+        assert_eq!(licenses[0].id(), "MIT");
+    }
+
+    #[test]
+    fn and_or_with_precedence() {
+        // OR binds looser than AND, so this parses as "(MIT AND Apache-2.0) OR 0BSD".
+        let expr = Expression::parse("MIT AND Apache-2.0 OR 0BSD").unwrap();
+        assert!(expr.is_satisfied_by(&[license("0BSD")]));
+        assert!(!expr.is_satisfied_by(&[license("MIT")]));
+        assert!(expr.is_satisfied_by(&[license("MIT"), license("Apache-2.0")]));
+    }
+
+    #[test]
+    fn license_ref_is_never_satisfied() {
+        let expr = Expression::parse("LicenseRef-My-Custom-License").unwrap();
+        assert!(!expr.is_satisfied_by(&[license("MIT")]));
+        assert_eq!(expr.iter_licenses().count(), 0);
+    }
+
+    #[test]
+    fn rejects_empty_input() {
+        assert_eq!(Expression::parse("").unwrap_err().kind, ErrorKind::UnexpectedEnd);
+    }
+
+    #[test]
+    fn rejects_unbalanced_parens() {
+        assert!(Expression::parse("(MIT").is_err());
+        assert!(Expression::parse("MIT)").is_err());
+    }
+
+    #[test]
+    fn rejects_trailing_garbage() {
+        assert!(Expression::parse("MIT MIT").is_err());
+    }
+
+    #[test]
+    fn rejects_with_on_a_compound_expression() {
+        let err = Expression::parse("(MIT AND Apache-2.0) WITH Classpath-exception-2.0").unwrap_err();
+        assert_eq!(err.kind, ErrorKind::UnexpectedToken);
+    }
+
+    #[test]
+    fn rejects_with_without_an_exception() {
+        let err = Expression::parse("MIT WITH MIT").unwrap_err();
+        assert_eq!(err.kind, ErrorKind::UnknownException);
+    }
+
+    #[test]
+    fn or_later_matches_later_versions_of_the_same_family() {
+        let expr = Expression::parse("GPL-2.0-only+").unwrap();
+        assert!(expr.is_satisfied_by(&[license("GPL-3.0-only")]));
+        assert!(!expr.is_satisfied_by(&[license("BSD-3-Clause")]));
+    }
+
+    #[test]
+    fn or_later_does_not_conflate_unrelated_ids() {
+        // BSD-2-Clause and BSD-3-Clause are different license texts, not
+        // versions of one license, so `+` must not bridge them.
+        let expr = Expression::parse("BSD-2-Clause+").unwrap();
+        assert!(!expr.is_satisfied_by(&[license("BSD-3-Clause")]));
+    }
+
+    #[test]
+    fn satisfying_licenses_dedupes_and_results() {
+        let expr = Expression::parse("MIT AND MIT").unwrap();
+        let held = [license("MIT")];
+        assert_eq!(expr.satisfying_licenses(&held).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn plus_before_with_still_matches_later_versions() {
+        let expr = Expression::parse("GPL-2.0-only+ WITH Classpath-exception-2.0").unwrap();
+        assert!(expr.is_satisfied_by(&[license("GPL-3.0-only")]));
+        assert!(!expr.is_satisfied_by(&[license("GPL-1.0-only")]));
+    }
+
+    #[test]
+    fn satisfying_licenses_prefers_the_smaller_or_branch() {
+        // Both `(MIT AND Apache-2.0)` and `0BSD` independently satisfy this,
+        // so the minimal set is the single-license `0BSD` match.
+        let expr = Expression::parse("(MIT AND Apache-2.0) OR 0BSD").unwrap();
+        let held = [license("MIT"), license("Apache-2.0"), license("0BSD")];
+        let satisfying = expr.satisfying_licenses(&held).unwrap();
+        assert_eq!(satisfying.len(), 1);
+        assert_eq!(satisfying[0].id(), "0BSD");
+    }
+}
@@ -221,6 +221,25 @@ impl Display for Limitations {
     }
 }
 
+/// The high-level category of the license.
+///
+/// Mirrors the broad groupings used by tools such as ScanCode, which is
+/// what most users filter on rather than the fine-grained conditions below.
+#[derive(Copy, Clone, Debug, Hash, Ord, PartialOrd, Eq, PartialEq)]
+pub enum Category {
+    /// Places the work in the public domain, granting no exclusive rights to waive.
+    PublicDomain,
+    /// Permits almost any use, including in proprietary works.
+    Permissive,
+    /// Requires modified files to be released under the same license.
+    WeakCopyleft,
+    /// Requires the whole derivative work to be released under the same
+    /// license; for some licenses (e.g. AGPL, OSL) this also covers use over a network.
+    Copyleft,
+    /// Not an open-source license.
+    Proprietary,
+}
+
 /// Extension trait for supported licenses.
 pub trait LicenseExt: License {
     /// The permissions of the license.
@@ -231,6 +250,9 @@ pub trait LicenseExt: License {
 
     /// The limitations of the license.
     fn limitations(&self) -> Limitations;
+
+    /// The high-level category of the license.
+    fn category(&self) -> Category;
 }
 
 macro_rules! impl_ext {
@@ -239,6 +261,7 @@ macro_rules! impl_ext {
             permissions: $($permissions:ident)|*;
             conditions: $($conditions:ident)|*;
             limitations: $($limitations:ident)|*;
+            category: $category:ident;
         })*
     ) => {
         $(impl LicenseExt for $struct {
@@ -265,6 +288,11 @@ macro_rules! impl_ext {
                     ..Default::default()
                 }
             }
+
+            #[inline]
+            fn category(&self) -> Category {
+                Category::$category
+            }
         })*
     };
 }
@@ -274,80 +302,122 @@ impl_ext! {
         permissions: commercial_use | distribution | modification | patent_rights | private_use;
         conditions:  document_changes | license_and_copyright_notice;
         limitations: no_liability | no_trademark_rights | no_warranty;
+        category: Permissive;
     }
     AGPL_3_0_only {
         permissions: commercial_use | distribution | modification | patent_rights | private_use;
         conditions: disclose_sources | document_changes | license_and_copyright_notice | network_use_is_distribution | same_license;
         limitations: no_liability | no_warranty;
+        category: Copyleft;
     }
     Apache_2_0 {
         permissions: commercial_use | distribution | modification | patent_rights | private_use;
         conditions: document_changes | license_and_copyright_notice;
         limitations: no_liability | no_trademark_rights | no_warranty;
+        category: Permissive;
     }
     BSD_0 {
         permissions: commercial_use | distribution | modification | private_use;
         conditions: ;
         limitations: no_liability | no_warranty;
+        category: Permissive;
     }
     BSD_2_Clause {
         permissions: commercial_use | distribution | modification | private_use;
         conditions: license_and_copyright_notice;
         limitations: no_liability | no_warranty;
+        category: Permissive;
     }
     BSD_3_Clause {
         permissions: commercial_use | distribution | modification | private_use;
         conditions: license_and_copyright_notice;
         limitations: no_liability | no_warranty;
+        category: Permissive;
     }
     BSD_3_Clause_Clear {
         permissions: commercial_use | distribution | modification | private_use;
         conditions: license_and_copyright_notice;
         limitations: no_liability | no_warranty | no_patent_rights;
+        category: Permissive;
     }
     BSL_1_0 {
         permissions: commercial_use | distribution | modification | private_use;
         conditions: license_and_copyright_notice;
         limitations: no_liability | no_warranty;
+        category: Permissive;
     }
     CC0_1_0 {
         permissions: commercial_use | distribution | modification | private_use;
         conditions: ;
         limitations: no_liability | no_trademark_rights | no_warranty | no_patent_rights;
+        category: PublicDomain;
     }
     GPL_3_0_only {
         permissions: commercial_use | distribution | modification | patent_rights | private_use;
         conditions: disclose_sources | document_changes | license_and_copyright_notice | same_license;
         limitations: no_liability | no_warranty;
+        category: Copyleft;
     }
     LGPL_3_0_only {
         permissions: commercial_use | distribution | modification | patent_rights | private_use;
         conditions: disclose_sources | document_changes | license_and_copyright_notice | same_license;
         limitations: no_liability | no_warranty;
+        category: WeakCopyleft;
     }
     MIT {
         permissions: commercial_use | distribution | modification | private_use;
         conditions: license_and_copyright_notice;
         limitations: no_liability | no_warranty;
+        category: Permissive;
     }
     MPL_2_0 {
         permissions: commercial_use | distribution | modification | patent_rights | private_use;
         conditions: disclose_sources | license_and_copyright_notice | same_license;
         limitations: no_liability | no_trademark_rights | no_warranty;
+        category: WeakCopyleft;
     }
     OSL_3_0 {
         permissions: commercial_use | distribution | modification | patent_rights | private_use;
         conditions: disclose_sources | document_changes | license_and_copyright_notice | network_use_is_distribution | same_license;
         limitations: no_liability | no_trademark_rights | no_warranty;
+        category: Copyleft;
     }
     Unlicense {
         permissions: commercial_use | distribution | modification | private_use;
         conditions: ;
         limitations: no_liability | no_warranty;
+        category: PublicDomain;
     }
     WTFPL {
         permissions: commercial_use | distribution | modification | private_use;
         conditions: ;
         limitations: ;
+        category: Permissive;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::licenses::{CC0_1_0, GPL_3_0_only, MIT, MPL_2_0};
+
+    #[test]
+    fn permissive_licenses_are_permissive() {
+        assert_eq!(MIT.category(), Category::Permissive);
+    }
+
+    #[test]
+    fn strong_copyleft_licenses_are_copyleft() {
+        assert_eq!(GPL_3_0_only.category(), Category::Copyleft);
+    }
+
+    #[test]
+    fn file_scoped_copyleft_licenses_are_weak_copyleft() {
+        assert_eq!(MPL_2_0.category(), Category::WeakCopyleft);
+    }
+
+    #[test]
+    fn cc0_is_public_domain() {
+        assert_eq!(CC0_1_0.category(), Category::PublicDomain);
     }
 }
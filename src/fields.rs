@@ -0,0 +1,125 @@
+//! Filling in license text placeholders.
+//!
+//! Requires the `alloc` feature.
+
+use alloc::string::String;
+
+/// The fields substituted into a license's placeholders by [`License::fill`](crate::License::fill)
+/// and [`License::fill_header`](crate::License::fill_header).
+///
+/// Recognizes the common SPDX placeholder spellings, both angle-bracket
+/// (`<year>`, `<copyright holders>`) and square-bracket (`[yyyy]`,
+/// `[name of copyright owner]`) forms, case-insensitively. Placeholders with
+/// no corresponding field are left untouched.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Fields {
+    year: Option<String>,
+    copyright_holder: Option<String>,
+    project: Option<String>,
+}
+
+impl Fields {
+    /// Creates an empty set of fields.
+    #[inline]
+    pub const fn new() -> Self {
+        Fields {
+            year: None,
+            copyright_holder: None,
+            project: None,
+        }
+    }
+
+    /// Sets the copyright year, substituted for `<year>`/`[yyyy]`-style placeholders.
+    #[inline]
+    pub fn year(mut self, year: impl Into<String>) -> Self {
+        self.year = Some(year.into());
+        self
+    }
+
+    /// Sets the copyright holder, substituted for `<copyright holders>`/`[name of copyright owner]`-style placeholders.
+    #[inline]
+    pub fn copyright_holder(mut self, copyright_holder: impl Into<String>) -> Self {
+        self.copyright_holder = Some(copyright_holder.into());
+        self
+    }
+
+    /// Sets the project name, substituted for `<project>`/`[name of program]`-style placeholders.
+    #[inline]
+    pub fn project(mut self, project: impl Into<String>) -> Self {
+        self.project = Some(project.into());
+        self
+    }
+
+    pub(crate) fn apply(&self, text: &str) -> String {
+        let mut out = String::with_capacity(text.len());
+        let mut rest = text;
+        while let Some(start) = rest.find(|c: char| c == '<' || c == '[') {
+            out.push_str(&rest[..start]);
+            let open = rest.as_bytes()[start] as char;
+            let close = if open == '<' { '>' } else { ']' };
+            let after_open = &rest[start + 1..];
+            match after_open.find(close) {
+                Some(end) => {
+                    let placeholder = &after_open[..end];
+                    match self.resolve(placeholder) {
+                        Some(value) => out.push_str(value),
+                        None => {
+                            out.push(open);
+                            out.push_str(placeholder);
+                            out.push(close);
+                        }
+                    }
+                    rest = &after_open[end + 1..];
+                }
+                None => {
+                    out.push(open);
+                    rest = after_open;
+                    break;
+                }
+            }
+        }
+        out.push_str(rest);
+        out
+    }
+
+    fn resolve(&self, placeholder: &str) -> Option<&str> {
+        let lower = placeholder.to_ascii_lowercase();
+        if lower.contains("year") || lower == "yyyy" {
+            self.year.as_deref()
+        } else if lower.contains("copyright holder") || lower.contains("name of copyright owner") || lower.contains("author") {
+            self.copyright_holder.as_deref()
+        } else if lower.contains("project") || lower.contains("name of program") {
+            self.project.as_deref()
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fills_known_placeholders_and_leaves_unknown_ones() {
+        let fields = Fields::new().year("2024").copyright_holder("Jane Doe");
+        let text = "Copyright (c) <year> <copyright holders>\n\
+[yyyy] [name of copyright owner]\n\
+All rights reserved, <unknown placeholder>.";
+        assert_eq!(
+            fields.apply(text),
+            "Copyright (c) 2024 Jane Doe\n2024 Jane Doe\nAll rights reserved, <unknown placeholder>."
+        );
+    }
+
+    #[test]
+    fn leaves_text_without_placeholders_untouched() {
+        assert_eq!(Fields::new().apply("Plain text."), "Plain text.");
+    }
+
+    #[test]
+    fn fills_the_project_name() {
+        let fields = Fields::new().project("crate");
+        assert_eq!(fields.apply("<project>"), "crate");
+    }
+}
@@ -0,0 +1,195 @@
+//! Identifying a license from its raw text.
+//!
+//! Requires the `alloc` feature.
+
+use crate::{licenses, License};
+use alloc::collections::BTreeSet;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// The default similarity threshold used by [`guess_from_text`].
+pub const DEFAULT_THRESHOLD: f64 = 0.9;
+
+/// Tries to identify which embedded license `text` is, e.g. the contents of a `LICENSE` file.
+///
+/// Returns `None` unless a license scores at least [`DEFAULT_THRESHOLD`]. Use
+/// [`guess_from_text_with_threshold`] to configure the threshold, or
+/// [`guess_from_text_ranked`] to see every candidate.
+///
+/// # Examples
+/// ```
+/// use license::{guess_from_text, License};
+///
+/// let mit: &dyn License = "MIT".parse().unwrap();
+/// let guess = guess_from_text(mit.text()).unwrap();
+/// assert_eq!(guess.id(), "MIT");
+/// ```
+#[inline]
+pub fn guess_from_text(text: &str) -> Option<&'static dyn License> {
+    guess_from_text_with_threshold(text, DEFAULT_THRESHOLD)
+}
+
+/// Like [`guess_from_text`], but with a configurable similarity `threshold` in the `0.0..=1.0` range.
+pub fn guess_from_text_with_threshold(text: &str, threshold: f64) -> Option<&'static dyn License> {
+    let (license, score) = guess_from_text_ranked(text, 1).into_iter().next()?;
+    (score >= threshold).then_some(license)
+}
+
+/// Ranks every embedded license by similarity to `text`, returning the top `n` `(license, score)`
+/// pairs in descending order of score.
+///
+/// Useful for disambiguating near-identical variants, such as the BSD family.
+pub fn guess_from_text_ranked(text: &str, n: usize) -> Vec<(&'static dyn License, f64)> {
+    let bigrams = word_bigrams(&normalize(text));
+    let mut ranked: Vec<_> = licenses::ALL
+        .iter()
+        .map(|license| {
+            let other = word_bigrams(&normalize(license.text()));
+            (*license, dice_coefficient(&bigrams, &other))
+        })
+        .collect();
+    ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+    ranked.truncate(n);
+    ranked
+}
+
+/// Normalizes license text so that unrelated copyright boilerplate and unfilled
+/// placeholders do not affect the comparison: lowercases the text, collapses
+/// whitespace, strips a leading copyright/year line, and removes `<...>`/`[...]` placeholders.
+fn normalize(text: &str) -> String {
+    // A title line (e.g. "MIT License") commonly comes before the copyright
+    // notice, so the notice isn't necessarily the first non-empty line; scan
+    // a window of leading lines for it instead of stopping at the first miss.
+    const LEADING_LINES_TO_SCAN: usize = 5;
+    let copyright_line = text
+        .lines()
+        .map(str::trim)
+        .take(LEADING_LINES_TO_SCAN)
+        .position(|line| !line.is_empty() && is_copyright_line(line));
+
+    let mut body = String::with_capacity(text.len());
+    for (i, line) in text.lines().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || Some(i) == copyright_line {
+            continue;
+        }
+        if !body.is_empty() {
+            body.push('\n');
+        }
+        body.push_str(trimmed);
+    }
+
+    let mut out = String::with_capacity(body.len());
+    let mut rest = body.as_str();
+    while let Some(start) = rest.find(|c: char| c == '<' || c == '[') {
+        out.push_str(&rest[..start]);
+        let close = if rest.as_bytes()[start] == b'<' { '>' } else { ']' };
+        let after_open = &rest[start + 1..];
+        match after_open.find(close) {
+            Some(end) => rest = &after_open[end + 1..],
+            None => {
+                rest = after_open;
+                break;
+            }
+        }
+    }
+    out.push_str(rest);
+
+    let mut normalized = String::with_capacity(out.len());
+    let mut prev_space = true;
+    for ch in out.chars() {
+        if ch.is_whitespace() {
+            if !prev_space {
+                normalized.push(' ');
+            }
+            prev_space = true;
+        } else {
+            normalized.push(ch.to_ascii_lowercase());
+            prev_space = false;
+        }
+    }
+    if normalized.ends_with(' ') {
+        normalized.pop();
+    }
+    normalized
+}
+
+fn is_copyright_line(line: &str) -> bool {
+    let lower = line.to_ascii_lowercase();
+    lower.contains("copyright") || lower.contains("(c)") || has_year(&lower)
+}
+
+fn has_year(s: &str) -> bool {
+    s.as_bytes().windows(4).any(|w| w.iter().all(u8::is_ascii_digit))
+}
+
+fn word_bigrams(text: &str) -> BTreeSet<String> {
+    let words: Vec<&str> = text.split(' ').filter(|word| !word.is_empty()).collect();
+    words.windows(2).map(|w| format!("{} {}", w[0], w[1])).collect()
+}
+
+fn dice_coefficient(a: &BTreeSet<String>, b: &BTreeSet<String>) -> f64 {
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    let intersection = a.intersection(b).count();
+    (2.0 * intersection as f64) / (a.len() + b.len()) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A realistic LICENSE file: the stock MIT template with its placeholders
+    // actually filled in, not the template text itself.
+    const MIT_LICENSE_FILE: &str = "MIT License\n\
+\n\
+Copyright (c) 2024 Jane Doe\n\
+\n\
+Permission is hereby granted, free of charge, to any person obtaining a copy\n\
+of this software and associated documentation files (the \"Software\"), to deal\n\
+in the Software without restriction, including without limitation the rights\n\
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell\n\
+copies of the Software, and to permit persons to whom the Software is\n\
+furnished to do so, subject to the following conditions:\n\
+\n\
+The above copyright notice and this permission notice shall be included in all\n\
+copies or substantial portions of the Software.\n\
+\n\
+THE SOFTWARE IS PROVIDED \"AS IS\", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR\n\
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,\n\
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE\n\
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER\n\
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,\n\
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE\n\
+SOFTWARE.\n";
+
+    #[test]
+    fn guesses_a_realistic_license_file() {
+        let guess = guess_from_text(MIT_LICENSE_FILE).unwrap();
+        assert_eq!(guess.id(), "MIT");
+    }
+
+    #[test]
+    fn with_threshold_rejects_dissimilar_text() {
+        assert!(guess_from_text_with_threshold("not a license at all", 0.9).is_none());
+    }
+
+    #[test]
+    fn ranked_returns_the_requested_number_of_candidates() {
+        assert_eq!(guess_from_text_ranked(MIT_LICENSE_FILE, 3).len(), 3);
+        assert_eq!(guess_from_text_ranked(MIT_LICENSE_FILE, 0).len(), 0);
+    }
+
+    #[test]
+    fn normalize_collapses_whitespace_and_case() {
+        assert_eq!(normalize("Hello   World\n\nFoo"), "hello world foo");
+    }
+
+    #[test]
+    fn normalize_strips_a_leading_copyright_line_after_a_title() {
+        let text = "My License\n\nCopyright (c) <year> <copyright holders>\n\nBody text here.";
+        assert_eq!(normalize(text), "my license body text here.");
+    }
+}
@@ -35,6 +35,9 @@
 #![doc(html_root_url = "https://docs.rs/license")]
 #![deny(missing_docs, unsafe_code)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 #[cfg(feature = "serde")]
 mod serde;
 
@@ -51,6 +54,24 @@ pub mod exceptions {
     include!(concat!(env!("OUT_DIR"), "/exceptions.rs"));
 }
 
+/// SPDX license expression parsing.
+///
+/// Requires the `alloc` feature.
+#[cfg(feature = "alloc")]
+pub mod expression;
+
+#[cfg(feature = "alloc")]
+mod guess;
+
+#[cfg(feature = "alloc")]
+pub use guess::{guess_from_text, guess_from_text_ranked, guess_from_text_with_threshold, DEFAULT_THRESHOLD};
+
+#[cfg(feature = "alloc")]
+mod fields;
+
+#[cfg(feature = "alloc")]
+pub use fields::Fields;
+
 /// Base functionality for all licenses.
 pub trait License {
     /// The identifier of the license.
@@ -87,6 +108,22 @@ pub trait License {
 
     /// Relevant sources.
     fn see_also(&self) -> &'static [&'static str];
+
+    /// Fills in the placeholders in [`text`](License::text) with `fields`.
+    ///
+    /// Requires the `alloc` feature.
+    #[cfg(feature = "alloc")]
+    fn fill(&self, fields: &Fields) -> alloc::string::String {
+        fields.apply(self.text())
+    }
+
+    /// Fills in the placeholders in [`header`](License::header) with `fields`.
+    ///
+    /// Requires the `alloc` feature.
+    #[cfg(feature = "alloc")]
+    fn fill_header(&self, fields: &Fields) -> Option<alloc::string::String> {
+        self.header().map(|header| fields.apply(header))
+    }
 }
 
 /// Base functionality for all license exceptions.